@@ -0,0 +1,171 @@
+#[cfg(feature = "checked")]
+use alloc::vec::Vec;
+#[cfg(feature = "checked")]
+use core::cell::Cell;
+#[cfg(feature = "checked")]
+use core::fmt;
+#[cfg(not(feature = "checked"))]
+use core::marker::PhantomData;
+#[cfg(feature = "checked")]
+use core::ops::{Deref, DerefMut};
+
+/// Keeps track of live borrows of the inner vector when the `checked` feature is enabled.
+/// Without it, this is zero-sized and every check is a no-op.
+#[derive(Default)]
+pub(crate) struct BorrowFlag {
+    /// `0` if unborrowed, `n > 0` for `n` shared borrows and `-1` for a mutable borrow.
+    #[cfg(feature = "checked")]
+    state: Cell<isize>,
+}
+
+impl BorrowFlag {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            #[cfg(feature = "checked")]
+            state: Cell::new(0),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn check_shared(&self) {
+        #[cfg(feature = "checked")]
+        assert!(
+            self.state.get() >= 0,
+            "VecCell accessed while it is mutably borrowed"
+        );
+    }
+
+    #[inline]
+    pub(crate) fn check_mut(&self) {
+        #[cfg(feature = "checked")]
+        assert!(
+            self.state.get() == 0,
+            "VecCell mutated while it is already borrowed"
+        );
+    }
+
+    /// Marks the inner vector as shared-borrowed until the guard is dropped.
+    #[inline]
+    pub(crate) fn shared(&self) -> BorrowGuard<'_> {
+        #[cfg(feature = "checked")]
+        {
+            let prev = self.state.get();
+            self.state.set(prev + 1);
+            BorrowGuard {
+                state: &self.state,
+                prev,
+            }
+        }
+        #[cfg(not(feature = "checked"))]
+        BorrowGuard {
+            _marker: PhantomData,
+        }
+    }
+
+    /// Marks the inner vector as mutably borrowed until the guard is dropped.
+    #[inline]
+    pub(crate) fn exclusive(&self) -> BorrowGuard<'_> {
+        #[cfg(feature = "checked")]
+        {
+            let prev = self.state.get();
+            self.state.set(-1);
+            BorrowGuard {
+                state: &self.state,
+                prev,
+            }
+        }
+        #[cfg(not(feature = "checked"))]
+        BorrowGuard {
+            _marker: PhantomData,
+        }
+    }
+
+    #[cfg(feature = "checked")]
+    #[inline]
+    pub(crate) fn try_exclusive(&self) -> Result<BorrowGuard<'_>, BorrowError> {
+        if self.state.get() == 0 {
+            Ok(self.exclusive())
+        } else {
+            Err(BorrowError { _priv: () })
+        }
+    }
+}
+
+pub(crate) struct BorrowGuard<'a> {
+    #[cfg(feature = "checked")]
+    state: &'a Cell<isize>,
+    #[cfg(feature = "checked")]
+    prev: isize,
+    #[cfg(not(feature = "checked"))]
+    _marker: PhantomData<&'a ()>,
+}
+
+#[cfg(feature = "checked")]
+impl Drop for BorrowGuard<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.state.set(self.prev);
+    }
+}
+
+/// A mutable borrow of the inner vector of a [`VecCell`](crate::VecCell), returned by
+/// [`VecCell::try_borrow_mut`](crate::VecCell::try_borrow_mut).
+///
+/// Any other access to the `VecCell` panics while this guard is alive.
+#[cfg(feature = "checked")]
+pub struct RefMut<'a, T> {
+    vec: &'a mut Vec<T>,
+    _guard: BorrowGuard<'a>,
+}
+
+#[cfg(feature = "checked")]
+impl<'a, T> RefMut<'a, T> {
+    #[inline]
+    pub(crate) fn new(vec: &'a mut Vec<T>, guard: BorrowGuard<'a>) -> Self {
+        Self { vec, _guard: guard }
+    }
+}
+
+#[cfg(feature = "checked")]
+impl<T> Deref for RefMut<'_, T> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.vec
+    }
+}
+
+#[cfg(feature = "checked")]
+impl<T> DerefMut for RefMut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.vec
+    }
+}
+
+#[cfg(feature = "checked")]
+impl<T: fmt::Debug> fmt::Debug for RefMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self.vec, f)
+    }
+}
+
+/// The error returned by [`VecCell::try_borrow_mut`](crate::VecCell::try_borrow_mut)
+/// if the `VecCell` is already borrowed.
+#[cfg(feature = "checked")]
+#[derive(Debug)]
+pub struct BorrowError {
+    _priv: (),
+}
+
+#[cfg(feature = "checked")]
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("VecCell is already borrowed")
+    }
+}
+
+#[cfg(feature = "checked")]
+impl core::error::Error for BorrowError {}
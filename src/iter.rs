@@ -1,13 +1,25 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
 use crate::VecCell;
 
 pub struct Iter<'a, T> {
     vc: &'a VecCell<T>,
     idx: usize,
+    /// Only fixed once `next_back` is called, so that forward iteration observes
+    /// elements pushed in the meantime.
+    back: Option<usize>,
+    done: bool,
 }
 
 impl<'a, T: Clone> Iter<'a, T> {
     pub(crate) fn new(vc: &'a VecCell<T>) -> Self {
-        Self { vc, idx: 0 }
+        Self {
+            vc,
+            idx: 0,
+            back: None,
+            done: false,
+        }
     }
 }
 
@@ -15,8 +27,269 @@ impl<'a, T: Clone> Iterator for Iter<'a, T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.idx >= self.back.unwrap_or_else(|| self.vc.len()) {
+            self.done = true;
+            return None;
+        }
+
         let item = self.vc.get(self.idx);
         self.idx += 1;
+        self.done = item.is_none();
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let len = self.vc.len();
+        let remaining = self.back.unwrap_or(len).min(len).saturating_sub(self.idx);
+        (remaining, Some(remaining))
+    }
+}
+
+/// Note that the length is computed from the current length of the [`VecCell`],
+/// so it reflects a live snapshot if the vector is modified during iteration.
+impl<'a, T: Clone> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T: Clone> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // The vector may have shrunk since the back cursor was fixed.
+        let len = self.vc.len();
+        let back = self.back.unwrap_or(len).min(len);
+        self.back = Some(back);
+
+        if self.done || back <= self.idx {
+            self.done = true;
+            return None;
+        }
+
+        self.back = Some(back - 1);
+        let item = self.vc.get(back - 1);
+        self.done = item.is_none();
         item
     }
 }
+
+impl<'a, T: Clone> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            vc: self.vc,
+            idx: self.idx,
+            back: self.back,
+            done: self.done,
+        }
+    }
+}
+
+pub struct Chunks<'a, T> {
+    vc: &'a VecCell<T>,
+    idx: usize,
+    size: usize,
+}
+
+impl<'a, T: Clone> Chunks<'a, T> {
+    pub(crate) fn new(vc: &'a VecCell<T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self { vc, idx: 0, size }
+    }
+}
+
+impl<'a, T: Clone> Iterator for Chunks<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.vc.with_ref(|vec| {
+            let end = vec.len().min(self.idx.saturating_add(self.size));
+            vec.get(self.idx..end)
+                .filter(|chunk| !chunk.is_empty())
+                .map(<[T]>::to_vec)
+        })?;
+
+        self.idx += chunk.len();
+        Some(chunk)
+    }
+}
+
+pub struct Windows<'a, T> {
+    vc: &'a VecCell<T>,
+    idx: usize,
+    size: usize,
+}
+
+impl<'a, T: Clone> Windows<'a, T> {
+    pub(crate) fn new(vc: &'a VecCell<T>, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        Self { vc, idx: 0, size }
+    }
+}
+
+impl<'a, T: Clone> Iterator for Windows<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.vc.with_ref(|vec| {
+            let end = self.idx.checked_add(self.size)?;
+            vec.get(self.idx..end).map(<[T]>::to_vec)
+        })?;
+        self.idx += 1;
+        Some(window)
+    }
+}
+
+pub struct RChunks<'a, T> {
+    vc: &'a VecCell<T>,
+    back: usize,
+    size: usize,
+}
+
+impl<'a, T: Clone> RChunks<'a, T> {
+    pub(crate) fn new(vc: &'a VecCell<T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self {
+            vc,
+            back: vc.len(),
+            size,
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for RChunks<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, chunk) = self.vc.with_ref(|vec| {
+            // The vector may have shrunk since the iterator was created.
+            let end = self.back.min(vec.len());
+            let start = end.saturating_sub(self.size);
+            (start, vec[start..end].to_vec())
+        });
+
+        if chunk.is_empty() {
+            return None;
+        }
+
+        self.back = start;
+        Some(chunk)
+    }
+}
+
+pub struct ChunksExact<'a, T> {
+    vc: &'a VecCell<T>,
+    idx: usize,
+    size: usize,
+}
+
+impl<'a, T: Clone> ChunksExact<'a, T> {
+    pub(crate) fn new(vc: &'a VecCell<T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self { vc, idx: 0, size }
+    }
+
+    /// Clones the elements at the end of the vector that do not fit into a full chunk.
+    pub fn remainder(&self) -> Vec<T> {
+        self.vc
+            .with_ref(|vec| vec[vec.len() - vec.len() % self.size..].to_vec())
+    }
+}
+
+impl<'a, T: Clone> Iterator for ChunksExact<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.vc.with_ref(|vec| {
+            let end = self.idx.checked_add(self.size)?;
+            vec.get(self.idx..end).map(<[T]>::to_vec)
+        })?;
+        self.idx += self.size;
+        Some(chunk)
+    }
+}
+
+pub struct Enumerate<'a, T> {
+    iter: Iter<'a, T>,
+}
+
+impl<'a, T: Clone> Enumerate<'a, T> {
+    pub(crate) fn new(vc: &'a VecCell<T>) -> Self {
+        Self {
+            iter: Iter::new(vc),
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for Enumerate<'a, T> {
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.iter.idx;
+        self.iter.next().map(|item| (idx, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for Enumerate<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for Enumerate<'a, T> {}
+
+pub struct StepIter<'a, T> {
+    vc: &'a VecCell<T>,
+    idx: usize,
+    step: usize,
+}
+
+impl<'a, T: Clone> StepIter<'a, T> {
+    pub(crate) fn new(vc: &'a VecCell<T>, step: usize) -> Self {
+        assert!(step != 0, "step must be non-zero");
+        Self { vc, idx: 0, step }
+    }
+}
+
+impl<'a, T: Clone> Iterator for StepIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.vc.get(self.idx)?;
+        self.idx = self.idx.saturating_add(self.step);
+        Some(item)
+    }
+}
+
+pub struct Peekable<'a, T> {
+    iter: Iter<'a, T>,
+    peeked: Option<Option<T>>,
+}
+
+impl<'a, T: Clone> Peekable<'a, T> {
+    pub(crate) fn new(vc: &'a VecCell<T>) -> Self {
+        Self {
+            iter: Iter::new(vc),
+            peeked: None,
+        }
+    }
+
+    /// Returns a reference to the next element without advancing the iterator.
+    ///
+    /// The element is cloned into an internal buffer, so later modifications of the
+    /// [`VecCell`] are not reflected in the peeked value.
+    pub fn peek(&mut self) -> Option<&T> {
+        self.peeked.get_or_insert_with(|| self.iter.next()).as_ref()
+    }
+}
+
+impl<'a, T: Clone> Iterator for Peekable<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(item) => item,
+            None => self.iter.next(),
+        }
+    }
+}
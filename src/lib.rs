@@ -1,65 +1,144 @@
-use std::cell::UnsafeCell;
-use std::collections::TryReserveError;
-use std::ops::RangeBounds;
-use std::vec::IntoIter;
+#![no_std]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
+extern crate alloc;
+#[doc(hidden)]
+pub extern crate alloc as __alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::{Allocator, Global};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::TryReserveError;
+use alloc::string::String;
+use alloc::vec::{IntoIter, Vec};
+use core::borrow::Borrow;
+use core::cell::UnsafeCell;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem::{self, MaybeUninit};
+use core::ops::RangeBounds;
+use core::ptr;
+
+mod borrow;
 mod iter;
-pub use iter::Iter;
+#[cfg(feature = "rand")]
+mod rand_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+use borrow::BorrowFlag;
+
+#[cfg(feature = "checked")]
+pub use borrow::{BorrowError, RefMut};
+pub use iter::{Chunks, ChunksExact, Enumerate, Iter, Peekable, RChunks, StepIter, Windows};
 
 #[macro_export]
 macro_rules! vec_cell {
-    ( $( $val:expr ),+ $( , )? ) => {{
-        let vc = $crate::VecCell::new();
-        vc.extend_from_slice(&[$( $val ),+]);
-        vc
-    }};
+    () => {
+        $crate::VecCell::new()
+    };
+    ( $val:expr ; $count:expr ) => {
+        $crate::VecCell::from($crate::__alloc::vec![$val; $count])
+    };
+    ( $( $val:expr ),+ $( , )? ) => {
+        $crate::VecCell::from([$( $val ),+])
+    };
 }
 
 /// A `Vec<T>` type that can be mutated with just a shared reference.
-#[derive(Debug, Default)]
-pub struct VecCell<T> {
+///
+/// With the `checked` feature enabled, reentrant accesses (e.g. mutating the `VecCell`
+/// from within a closure passed to one of its methods) panic instead of causing UB.
+///
+/// With the (nightly-only) `allocator_api` feature enabled, `VecCell` is generic over the
+/// allocator of the inner vector. The iterators and the conversions from and to other
+/// types are only available for the default `Global` allocator.
+pub struct VecCell<T, #[cfg(feature = "allocator_api")] A: Allocator = Global> {
+    #[cfg(not(feature = "allocator_api"))]
     inner: UnsafeCell<Vec<T>>,
+    #[cfg(feature = "allocator_api")]
+    inner: UnsafeCell<Vec<T, A>>,
+    borrow: BorrowFlag,
+}
+
+impl<T> Default for VecCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for VecCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.with_ref(|vec| fmt::Debug::fmt(vec, f))
+    }
 }
 
 impl<T: Clone> Clone for VecCell<T> {
     fn clone(&self) -> Self {
-        Self {
-            inner: UnsafeCell::new(unsafe { self.as_ref().clone() }),
-        }
+        Self::from(self.snapshot())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        let vec = self.get_mut();
+        source.with_ref(|source| vec.clone_from(source))
     }
 }
 
+/// Expands to `$ty<$t>`, or to `$ty<$t, A>` with the `allocator_api` feature enabled,
+/// for use in impls that are generic over the allocator.
+#[cfg(not(feature = "allocator_api"))]
+macro_rules! alloc_generic {
+    ($ty:ident<$t:ty>) => {
+        $ty<$t>
+    };
+}
+
+#[cfg(feature = "allocator_api")]
+macro_rules! alloc_generic {
+    ($ty:ident<$t:ty>) => {
+        $ty<$t, A>
+    };
+}
+
 macro_rules! delegate_method {
-    (#[doc = $d:expr] $m:ident( $( $n:ident : $nt:ty ),* ) -> $t:ty $( where T: $bound:tt )? ) => {
+    ($via:ident #[doc = $d:expr] $( #[$attr:meta] )* $m:ident $( < $( $g:ident : $gb:path ),* > )? ( $( $n:ident : $nt:ty ),* ) -> $t:ty $( where T: $bound:tt )? ) => {
         #[doc = $d]
+        $( #[$attr] )*
         #[inline]
-        pub fn $m(&self, $( $n: $nt ),*) -> $t
+        pub fn $m $( < $( $g: $gb ),* > )? (&self, $( $n: $nt ),*) -> $t
         $(
             where T: $bound
         )?
         {
-            unsafe { self.as_mut().$m($( $n ),*) }
+            self.$via(|vec| vec.$m($( $n ),*))
         }
     };
 }
 
 macro_rules! delegate_vec_methods {
-    ($( $m:ident( $( $n:ident : $nt:ty ),* ) -> $t:ty $( where T: $bound:tt )? ),*) => {
+    ($via:ident; $( $( #[$attr:meta] )* $m:ident $( < $( $g:ident : $gb:path ),* > )? ( $( $n:ident : $nt:ty ),* ) -> $t:ty $( where T: $bound:tt )? ),*) => {
         $(
             delegate_method! {
-                #[doc = concat!(" See [Vec::", stringify!($m), "](std::vec::Vec::", stringify!($m), ") for more information.")]
-                $m( $( $n : $nt ),* ) -> $t $( where T: $bound )?
+                $via
+                #[doc = concat!(" See [Vec::", stringify!($m), "](Vec::", stringify!($m), ") for more information.")]
+                $( #[$attr] )*
+                $m $( < $( $g : $gb ),* > )? ( $( $n : $nt ),* ) -> $t $( where T: $bound )?
             }
         )*
     }
 }
 
 macro_rules! delegate_slice_methods {
-    ($( $m:ident( $( $n:ident : $nt:ty ),* ) -> $t:ty $( where T: $bound:tt )? ),*) => {
+    ($via:ident; $( $( #[$attr:meta] )* $m:ident $( < $( $g:ident : $gb:path ),* > )? ( $( $n:ident : $nt:ty ),* ) -> $t:ty $( where T: $bound:tt )? ),*) => {
         $(
             delegate_method! {
+                $via
                 #[doc = concat!(" See [slice::", stringify!($m), "](slice::", stringify!($m), ") for more information.")]
-                $m( $( $n : $nt ),* ) -> $t $( where T: $bound )?
+                $( #[$attr] )*
+                $m $( < $( $g : $gb ),* > )? ( $( $n : $nt ),* ) -> $t $( where T: $bound )?
             }
         )*
     }
@@ -69,142 +148,2572 @@ impl<T> VecCell<T> {
     pub fn new() -> Self {
         Self {
             inner: UnsafeCell::new(Vec::new()),
+            borrow: BorrowFlag::new(),
         }
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             inner: UnsafeCell::new(Vec::with_capacity(capacity)),
+            borrow: BorrowFlag::new(),
         }
     }
 
+    /// Like [`with_capacity`](Self::with_capacity), but returns an error instead of
+    /// panicking or aborting if the allocation fails.
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        let mut vec = Vec::new();
+        vec.try_reserve_exact(capacity)?;
+        Ok(Self::from(vec))
+    }
+
+    /// Mutably borrows the inner vector until the returned guard is dropped,
+    /// or returns an error if it is currently borrowed.
+    #[cfg(feature = "checked")]
+    pub fn try_borrow_mut(&self) -> Result<RefMut<'_, T>, BorrowError> {
+        let guard = self.borrow.try_exclusive()?;
+        Ok(RefMut::new(unsafe { &mut *self.inner.get() }, guard))
+    }
+
+    /// Converts the `VecCell` into a `Box<[T]>`, dropping any excess capacity.
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.into_inner().into_boxed_slice()
+    }
+
+    /// Consumes and leaks the `VecCell`, returning a mutable reference to its contents.
+    ///
+    /// See [Vec::leak](Vec::leak) for more information.
+    #[inline]
+    pub fn leak<'a>(self) -> &'a mut [T] {
+        self.into_inner().leak()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> iter::Iter<'_, T>
+    where
+        T: Clone,
+    {
+        iter::Iter::new(self)
+    }
+
+    /// Returns an iterator over the indices and cloned elements.
+    #[inline]
+    pub fn enumerate(&self) -> iter::Enumerate<'_, T>
+    where
+        T: Clone,
+    {
+        iter::Enumerate::new(self)
+    }
+
+    /// Returns an iterator over every `step`-th cloned element, starting at the first one.
+    ///
+    /// # Panics
+    /// Panics if `step` is 0.
+    #[inline]
+    pub fn iter_step(&self, step: usize) -> iter::StepIter<'_, T>
+    where
+        T: Clone,
+    {
+        iter::StepIter::new(self, step)
+    }
+
+    /// Returns an iterator over the cloned elements that can [`peek`](Peekable::peek)
+    /// at the next element.
+    #[inline]
+    pub fn iter_peekable(&self) -> iter::Peekable<'_, T>
+    where
+        T: Clone,
+    {
+        iter::Peekable::new(self)
+    }
+
+    /// Returns an iterator over cloned chunks of `size` elements, starting at the beginning.
+    /// The last chunk may be shorter.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    #[inline]
+    pub fn chunks(&self, size: usize) -> iter::Chunks<'_, T>
+    where
+        T: Clone,
+    {
+        iter::Chunks::new(self, size)
+    }
+
+    /// Returns an iterator over cloned chunks of `size` elements, starting at the end.
+    /// The last chunk may be shorter.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
     #[inline]
-    pub unsafe fn as_ref(&self) -> &Vec<T> {
+    pub fn rchunks(&self, size: usize) -> iter::RChunks<'_, T>
+    where
+        T: Clone,
+    {
+        iter::RChunks::new(self, size)
+    }
+
+    /// Returns an iterator over cloned chunks of exactly `size` elements, starting at the beginning.
+    /// The elements that do not fit into a full chunk can be retrieved with
+    /// [`ChunksExact::remainder`].
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    #[inline]
+    pub fn chunks_exact(&self, size: usize) -> iter::ChunksExact<'_, T>
+    where
+        T: Clone,
+    {
+        iter::ChunksExact::new(self, size)
+    }
+
+    /// Returns an iterator over cloned overlapping windows of `size` elements.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    #[inline]
+    pub fn windows(&self, size: usize) -> iter::Windows<'_, T>
+    where
+        T: Clone,
+    {
+        iter::Windows::new(self, size)
+    }
+
+    delegate_vec_methods! {
+        with_mut;
+        split_off(at: usize) -> Vec<T>
+    }
+}
+
+impl<T, #[cfg(feature = "allocator_api")] A: Allocator> alloc_generic!(VecCell<T>) {
+    /// Returns a shared reference to the inner vector.
+    ///
+    /// # Safety
+    /// The inner vector must not be mutated while the returned reference is alive.
+    #[inline]
+    pub unsafe fn as_ref(&self) -> &alloc_generic!(Vec<T>) {
+        self.borrow.check_shared();
         &*self.inner.get()
     }
 
+    /// Returns a mutable reference to the inner vector.
+    ///
+    /// # Safety
+    /// No other reference to the inner vector or any of its elements may exist while the returned reference is alive.
     #[inline]
-    pub unsafe fn as_mut(&self) -> &mut Vec<T> {
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn as_mut(&self) -> &mut alloc_generic!(Vec<T>) {
+        self.borrow.check_mut();
         &mut *self.inner.get()
     }
 
+    /// Returns a shared reference to the elements of the inner vector.
+    ///
+    /// # Safety
+    /// The inner vector must not be mutated while the returned reference is alive.
+    #[inline]
+    pub unsafe fn as_slice(&self) -> &[T] {
+        self.as_ref()
+    }
+
+    /// Returns a mutable reference to the elements of the inner vector.
+    ///
+    /// # Safety
+    /// No other reference to the inner vector or any of its elements may exist while the returned reference is alive.
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn as_mut_slice(&self) -> &mut [T] {
+        self.as_mut()
+    }
+
+    /// Returns the spare capacity of the inner vector as a slice of `MaybeUninit<T>`.
+    ///
+    /// See [Vec::spare_capacity_mut](Vec::spare_capacity_mut) for more information.
+    ///
+    /// # Safety
+    /// The inner vector must not be accessed in any other way while the returned reference is alive.
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn spare_capacity_mut(&self) -> &mut [MaybeUninit<T>] {
+        self.as_mut().spare_capacity_mut()
+    }
+
+    /// Sets the length of the inner vector.
+    ///
+    /// See [Vec::set_len](Vec::set_len) for more information.
+    ///
+    /// # Safety
+    /// The same requirements as for `Vec::set_len` apply: `new_len` must not exceed the capacity,
+    /// and the elements up to `new_len` must be initialized.
+    #[inline]
+    pub unsafe fn set_len(&self, new_len: usize) {
+        self.as_mut().set_len(new_len)
+    }
+
+    /// Calls `f` with a mutable reference to the inner vector and returns its result.
+    ///
+    /// `f` must not access this `VecCell` in any way (e.g. by calling `push` on it),
+    /// as that would alias the mutable reference handed to `f`, which is UB.
+    /// With the `checked` feature enabled, doing so panics.
+    #[inline]
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut alloc_generic!(Vec<T>)) -> R) -> R {
+        let vec = unsafe { self.as_mut() };
+        let _guard = self.borrow.exclusive();
+        f(vec)
+    }
+
+    /// Calls `f` with a shared reference to the inner vector and returns its result.
+    ///
+    /// `f` may read from this `VecCell`, but must not mutate it.
+    #[inline]
+    fn with_ref<R>(&self, f: impl FnOnce(&alloc_generic!(Vec<T>)) -> R) -> R {
+        let vec = unsafe { self.as_ref() };
+        let _guard = self.borrow.shared();
+        f(vec)
+    }
+
+    /// Returns a reference to the element at `index`.
+    ///
+    /// # Safety
+    /// The inner vector must not be mutated while the returned reference is alive.
     #[inline]
     pub unsafe fn get_ref(&self, index: usize) -> Option<&T> {
         self.as_ref().get(index)
     }
 
     #[inline]
-    pub fn into_inner(self) -> Vec<T> {
+    pub fn into_inner(self) -> alloc_generic!(Vec<T>) {
         self.inner.into_inner()
     }
 
+    /// Returns a mutable reference to the inner vector.
+    ///
+    /// This is safe because the `&mut self` receiver statically guarantees exclusive access.
     #[inline]
-    pub fn iter(&self) -> iter::Iter<'_, T>
+    pub fn get_mut(&mut self) -> &mut alloc_generic!(Vec<T>) {
+        self.inner.get_mut()
+    }
+
+    /// Returns an iterator over a copy of the elements, taken once up front.
+    ///
+    /// Unlike [`iter`](Self::iter), later modifications of the `VecCell` are not observed.
+    pub fn iter_copied(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Copy,
+    {
+        self.to_vec().into_iter()
+    }
+
+    /// Clones the whole inner vector at once.
+    ///
+    /// This is the recommended way to iterate over large `VecCell`s: the returned `Vec`
+    /// is detached from the cell, so it can be iterated freely even if the cell is
+    /// mutated in the meantime, and the cost of cloning is paid in a single pass.
+    pub fn snapshot(&self) -> Vec<T>
     where
         T: Clone,
     {
-        iter::Iter::new(self)
+        self.with_ref(|vec| vec.to_vec())
+    }
+
+    /// Clones the whole inner vector at once, like [`slice::to_vec`].
+    ///
+    /// This is the same as [`snapshot`](Self::snapshot).
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.snapshot()
     }
 
     pub fn get(&self, index: usize) -> Option<T>
     where
         T: Clone,
     {
-        unsafe { self.as_ref().get(index).cloned() }
+        self.with_ref(|vec| vec.get(index).cloned())
     }
 
     pub fn first(&self) -> Option<T>
     where
         T: Clone,
     {
-        unsafe { self.as_ref().first().cloned() }
+        self.with_ref(|vec| vec.first().cloned())
     }
 
     pub fn last(&self) -> Option<T>
     where
         T: Clone,
     {
-        unsafe { self.as_ref().last().cloned() }
+        self.with_ref(|vec| vec.last().cloned())
+    }
+
+    /// Clones the first `N` elements into an array, or returns `None` if there are fewer than `N` elements.
+    pub fn first_chunk<const N: usize>(&self) -> Option<[T; N]>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| vec.first_chunk().cloned())
+    }
+
+    /// Clones the last `N` elements into an array, or returns `None` if there are fewer than `N` elements.
+    pub fn last_chunk<const N: usize>(&self) -> Option<[T; N]>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| vec.last_chunk().cloned())
+    }
+
+    /// Clones the first element and the remaining elements, or returns `None` if the vector is empty.
+    pub fn split_first_cloned(&self) -> Option<(T, Vec<T>)>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| {
+            let (first, rest) = vec.split_first()?;
+            Some((first.clone(), rest.to_vec()))
+        })
+    }
+
+    /// Clones the last element and the preceding elements, or returns `None` if the vector is empty.
+    pub fn split_last_cloned(&self) -> Option<(T, Vec<T>)>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| {
+            let (last, init) = vec.split_last()?;
+            Some((last.clone(), init.to_vec()))
+        })
+    }
+
+    /// Clones up to the first `n` elements.
+    pub fn take_first(&self, n: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| vec[..n.min(vec.len())].to_vec())
+    }
+
+    /// Clones up to the last `n` elements.
+    pub fn take_last(&self, n: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| vec[vec.len().saturating_sub(n)..].to_vec())
+    }
+
+    /// Clones the elements at each of the given indices into an array.
+    ///
+    /// Returns `None` if any index is out of bounds. Indices do not need to be distinct.
+    pub fn get_many_cloned<const N: usize>(&self, indices: [usize; N]) -> Option<[T; N]>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| {
+            if indices.iter().any(|&index| index >= vec.len()) {
+                return None;
+            }
+
+            Some(indices.map(|index| vec[index].clone()))
+        })
+    }
+
+    /// Clones the elements before and after `mid` into two vectors.
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    pub fn split_at_cloned(&self, mid: usize) -> (Vec<T>, Vec<T>)
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| {
+            let (left, right) = vec.split_at(mid);
+            (left.to_vec(), right.to_vec())
+        })
     }
 
     pub fn drain_collect<R: RangeBounds<usize>>(&self, range: R) -> Vec<T> {
-        unsafe { self.as_mut().drain(range).collect() }
+        self.with_mut(|vec| vec.drain(range).collect())
     }
 
     pub fn drain<R: RangeBounds<usize>>(&self, range: R) {
-        unsafe { drop(self.as_mut().drain(range)) }
+        self.with_mut(|vec| drop(vec.drain(range)))
     }
 
-    delegate_vec_methods! {
-        capacity() -> usize,
-        insert(index: usize, value: T) -> (),
-        pop() -> Option<T>,
-        push(value: T) -> (),
-        reserve(additional: usize) -> (),
-        reserve_exact(additional: usize) -> (),
-        shrink_to(min_capacity: usize) -> (),
-        swap_remove(index: usize) -> T,
-        truncate(len: usize) -> (),
-        dedup() -> () where T: PartialEq,
-        extend(it: impl IntoIterator<Item = T>) -> () where T: Clone,
-        extend_from_slice(other: &[T]) -> () where T: Clone,
-        remove(index: usize) -> T,
-        resize(new_len: usize, value: T) -> () where T: Clone,
-        split_off(at: usize) -> Vec<T>,
-        try_reserve(additional: usize) -> Result<(), TryReserveError>
+    /// Replaces `range` with the elements of `replace_with` and returns the removed elements.
+    ///
+    /// See [Vec::splice](Vec::splice) for more information.
+    /// Unlike `Vec::splice`, the removed elements are collected eagerly.
+    ///
+    /// `replace_with` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+    pub fn splice<R, I>(&self, range: R, replace_with: I) -> Vec<T>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        self.with_mut(|vec| vec.splice(range, replace_with).collect())
     }
 
-    delegate_slice_methods! {
-        len() -> usize,
-        is_empty() -> bool,
-        as_ptr() -> *const T,
-        as_mut_ptr() -> *mut T,
-        binary_search(x: &T) -> Result<usize, usize> where T: Ord,
-        contains(x: &T) -> bool where T: PartialEq,
-        fill(value: T) -> () where T: Clone,
-        reverse() -> (),
-        rotate_left(mid: usize) -> (),
-        rotate_right(k: usize) -> (),
-        sort() -> () where T: Ord,
-        sort_unstable() -> () where T: Ord,
-        starts_with(other: &[T]) -> bool where T: PartialEq,
-        swap(a: usize, b: usize) -> ()
+    /// Inserts all of `items` at `index`, shifting the following elements only once.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    pub fn insert_slice(&self, index: usize, items: &[T])
+    where
+        T: Clone,
+    {
+        self.with_mut(|vec| drop(vec.splice(index..index, items.iter().cloned())))
     }
-}
 
-impl<T> From<Vec<T>> for VecCell<T> {
-    fn from(vec: Vec<T>) -> Self {
-        Self {
-            inner: UnsafeCell::new(vec),
+    /// Inserts `value` at the front. This is O(n), as all elements need to be shifted.
+    #[inline]
+    pub fn push_front(&self, value: T) {
+        self.insert(0, value)
+    }
+
+    /// Inserts all of `items` at the front. This is O(n), as all elements need to be shifted.
+    #[inline]
+    pub fn prepend(&self, items: &[T])
+    where
+        T: Clone,
+    {
+        self.insert_slice(0, items)
+    }
+
+    /// Replaces the element at `index` with `value` and returns the old element.
+    ///
+    /// Returns `None` (and drops `value`) if `index` is out of bounds.
+    pub fn set(&self, index: usize, value: T) -> Option<T> {
+        self.with_mut(|vec| vec.get_mut(index).map(|slot| mem::replace(slot, value)))
+    }
+
+    /// Calls `f` with a mutable reference to the element at `index` and returns its result,
+    /// or `None` if `index` is out of bounds.
+    ///
+    /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+    pub fn update<R>(&self, index: usize, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        self.with_mut(|vec| vec.get_mut(index).map(f))
+    }
+
+    /// Moves all elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` are the same `VecCell`.
+    pub fn append(&self, other: &Self) {
+        assert!(!ptr::eq(self, other), "cannot append a VecCell to itself");
+        self.with_mut(|vec| other.with_mut(|other| vec.append(other)))
+    }
+
+    /// Swaps the contents of `self` and `other`.
+    ///
+    /// Does nothing if `self` and `other` are the same `VecCell`.
+    pub fn swap_cells(&self, other: &Self) {
+        if ptr::eq(self, other) {
+            return;
         }
+
+        self.with_mut(|vec| other.with_mut(|other| mem::swap(vec, other)))
     }
-}
 
-impl<T> From<VecCell<T>> for Vec<T> {
-    fn from(vec_cell: VecCell<T>) -> Self {
-        vec_cell.into_inner()
+    /// Returns the index of the first element matching `pred`.
+    ///
+    /// `pred` must not mutate this `VecCell`.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.with_ref(|vec| vec.iter().position(pred))
     }
-}
 
-impl<T> IntoIterator for VecCell<T> {
-    type Item = T;
-    type IntoIter = IntoIter<T>;
+    /// Returns the index of the last element matching `pred`.
+    ///
+    /// `pred` must not mutate this `VecCell`.
+    pub fn rposition(&self, pred: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.with_ref(|vec| vec.iter().rposition(pred))
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.into_inner().into_iter()
+    /// Clones the first element matching `pred`.
+    ///
+    /// `pred` must not mutate this `VecCell`.
+    pub fn find_cloned(&self, mut pred: impl FnMut(&T) -> bool) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| vec.iter().find(|x| pred(x)).cloned())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns the number of elements matching `pred`.
+    ///
+    /// `pred` must not mutate this `VecCell`.
+    pub fn count(&self, mut pred: impl FnMut(&T) -> bool) -> usize {
+        self.with_ref(|vec| vec.iter().filter(|x| pred(x)).count())
+    }
 
-    #[test]
-    fn it_works() {
-        let x: VecCell<u8> = vec_cell![1, 2, 3];
-        x.push(12);
-        x.push(34);
+    /// Returns `true` if any element matches `pred`.
+    ///
+    /// `pred` must not mutate this `VecCell`.
+    pub fn any(&self, pred: impl FnMut(&T) -> bool) -> bool {
+        self.with_ref(|vec| vec.iter().any(pred))
+    }
 
-        assert_eq!(x.into_inner().as_slice(), &[1, 2, 3, 12, 34]);
+    /// Returns `true` if all elements match `pred`.
+    ///
+    /// `pred` must not mutate this `VecCell`.
+    pub fn all(&self, pred: impl FnMut(&T) -> bool) -> bool {
+        self.with_ref(|vec| vec.iter().all(pred))
+    }
+
+    /// Clones the minimum element. If several elements are equally minimum, the first one is returned.
+    pub fn min_cloned(&self) -> Option<T>
+    where
+        T: Ord + Clone,
+    {
+        self.with_ref(|vec| vec.iter().min().cloned())
+    }
+
+    /// Clones the maximum element. If several elements are equally maximum, the last one is returned.
+    pub fn max_cloned(&self) -> Option<T>
+    where
+        T: Ord + Clone,
+    {
+        self.with_ref(|vec| vec.iter().max().cloned())
+    }
+
+    /// Clones the element with the minimum key. If several elements are equally minimum,
+    /// the first one is returned.
+    ///
+    /// `f` must not mutate this `VecCell`.
+    pub fn min_by_key<K: Ord>(&self, mut f: impl FnMut(&T) -> K) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| vec.iter().min_by_key(|x| f(x)).cloned())
+    }
+
+    /// Clones the element with the maximum key. If several elements are equally maximum,
+    /// the last one is returned.
+    ///
+    /// `f` must not mutate this `VecCell`.
+    pub fn max_by_key<K: Ord>(&self, mut f: impl FnMut(&T) -> K) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| vec.iter().max_by_key(|x| f(x)).cloned())
+    }
+
+    /// Creates a new `VecCell` by calling `f` on every element.
+    ///
+    /// `f` must not mutate this `VecCell`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> VecCell<U> {
+        self.with_ref(|vec| vec.iter().map(f).collect())
+    }
+
+    /// Calls `f` on every element and collects the `Some` results.
+    ///
+    /// `f` must not mutate this `VecCell`.
+    pub fn filter_map_collect<U>(&self, f: impl FnMut(&T) -> Option<U>) -> Vec<U> {
+        self.with_ref(|vec| vec.iter().filter_map(f).collect())
+    }
+
+    /// Calls `f` with the index and a reference to every element.
+    ///
+    /// `f` must not mutate this `VecCell`.
+    pub fn for_each_indexed(&self, mut f: impl FnMut(usize, &T)) {
+        self.with_ref(|vec| vec.iter().enumerate().for_each(|(i, x)| f(i, x)))
+    }
+
+    /// Folds every element into an accumulator, starting with `init`.
+    ///
+    /// `f` must not mutate this `VecCell`.
+    pub fn fold<B>(&self, init: B, f: impl FnMut(B, &T) -> B) -> B {
+        self.with_ref(|vec| vec.iter().fold(init, f))
+    }
+
+    /// Folds every element into an accumulator, starting with a clone of the first element.
+    /// Returns `None` if the vector is empty.
+    ///
+    /// `f` must not mutate this `VecCell`.
+    pub fn reduce(&self, f: impl FnMut(T, &T) -> T) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| {
+            let (first, rest) = vec.split_first()?;
+            Some(rest.iter().fold(first.clone(), f))
+        })
+    }
+
+    /// Reorders the vector such that the element at `index` is at its final sorted position,
+    /// and returns clones of the elements before it, the element itself and the elements after it.
+    ///
+    /// See [slice::select_nth_unstable] for more information.
+    pub fn select_nth_unstable(&self, index: usize) -> (Vec<T>, T, Vec<T>)
+    where
+        T: Ord + Clone,
+    {
+        self.with_mut(|vec| {
+            let (before, nth, after) = vec.select_nth_unstable(index);
+            (before.to_vec(), nth.clone(), after.to_vec())
+        })
+    }
+
+    /// Appends `value` if there is spare capacity, otherwise returns it back as `Err`.
+    ///
+    /// This never reallocates, mirroring the unstable `Vec::push_within_capacity`.
+    pub fn push_within_capacity(&self, value: T) -> Result<(), T> {
+        self.with_mut(|vec| {
+            if vec.len() == vec.capacity() {
+                return Err(value);
+            }
+
+            vec.push(value);
+            Ok(())
+        })
+    }
+
+    /// Removes and returns the first element matching `pred`.
+    ///
+    /// `pred` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+    pub fn remove_first_match(&self, pred: impl FnMut(&T) -> bool) -> Option<T> {
+        self.with_mut(|vec| {
+            let index = vec.iter().position(pred)?;
+            Some(vec.remove(index))
+        })
+    }
+
+    /// Removes all elements matching `pred` in a single pass and returns them in order.
+    ///
+    /// `pred` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+    pub fn remove_all_matching(&self, mut pred: impl FnMut(&T) -> bool) -> Vec<T> {
+        self.extract_if(|x| pred(x))
+    }
+
+    /// Removes all elements matching `pred` in a single pass and returns them in order.
+    /// Unlike [`remove_all_matching`](Self::remove_all_matching), `pred` may mutate the elements.
+    ///
+    /// See [Vec::extract_if](Vec::extract_if) for more information.
+    /// Unlike `Vec::extract_if`, the removed elements are collected eagerly.
+    ///
+    /// `pred` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+    pub fn extract_if(&self, pred: impl FnMut(&mut T) -> bool) -> Vec<T> {
+        self.with_mut(|vec| vec.extract_if(.., pred).collect())
+    }
+
+    /// Renders every element using its `Display` impl, placing `sep` between each of them.
+    pub fn display_join(&self, sep: &str) -> String
+    where
+        T: fmt::Display,
+    {
+        use fmt::Write;
+
+        self.with_ref(|vec| {
+            let mut out = String::new();
+            for (i, x) in vec.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(sep);
+                }
+                write!(out, "{x}").unwrap();
+            }
+            out
+        })
+    }
+
+    /// Clones the elements matching `pred` into the first `VecCell` and the rest into the second.
+    ///
+    /// `pred` must not mutate this `VecCell`.
+    pub fn partition(&self, mut pred: impl FnMut(&T) -> bool) -> (VecCell<T>, VecCell<T>)
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| {
+            let (matching, rest): (Vec<T>, Vec<T>) = vec.iter().cloned().partition(|x| pred(x));
+            (matching.into(), rest.into())
+        })
+    }
+
+    /// Clones pairs of elements of `self` and `other`, up to the length of the shorter one.
+    pub fn zip_cloned<U: Clone>(&self, other: &VecCell<U>) -> Vec<(T, U)>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| {
+            other.with_ref(|other| vec.iter().cloned().zip(other.iter().cloned()).collect())
+        })
+    }
+
+    /// Clones the elements into groups of consecutive elements with equal keys.
+    ///
+    /// `key` must not mutate this `VecCell`.
+    pub fn chunk_by<K: PartialEq>(&self, mut key: impl FnMut(&T) -> K) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| {
+            let mut groups: Vec<Vec<T>> = Vec::new();
+            let mut last_key = None;
+            for x in vec {
+                let k = key(x);
+                match groups.last_mut() {
+                    Some(group) if last_key.as_ref() == Some(&k) => group.push(x.clone()),
+                    _ => groups.push(alloc::vec![x.clone()]),
+                }
+                last_key = Some(k);
+            }
+            groups
+        })
+    }
+
+    /// Appends all of `items` and returns the index of the first appended element,
+    /// i.e. the length before appending.
+    pub fn push_slice(&self, items: &[T]) -> usize
+    where
+        T: Clone,
+    {
+        self.with_mut(|vec| {
+            let start = vec.len();
+            vec.extend_from_slice(items);
+            start
+        })
+    }
+
+    /// Appends `value` and returns its index.
+    pub fn push_and_index(&self, value: T) -> usize {
+        self.with_mut(|vec| {
+            vec.push(value);
+            vec.len() - 1
+        })
+    }
+
+    /// Removes and returns the element at `index`, replacing it with the last element.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn try_swap_remove(&self, index: usize) -> Option<T> {
+        self.with_mut(|vec| (index < vec.len()).then(|| vec.swap_remove(index)))
+    }
+
+    /// Removes and returns the element at `index`, shifting all elements after it to the left.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn try_remove(&self, index: usize) -> Option<T> {
+        self.with_mut(|vec| (index < vec.len()).then(|| vec.remove(index)))
+    }
+
+    /// Inserts `value` at `index`, shifting all elements after it to the right.
+    ///
+    /// Returns `value` back if `index > len`.
+    pub fn try_insert(&self, index: usize, value: T) -> Result<(), T> {
+        self.with_mut(|vec| {
+            if index > vec.len() {
+                return Err(value);
+            }
+
+            vec.insert(index, value);
+            Ok(())
+        })
+    }
+
+    /// Clones the element at `index`, first growing the vector to `index + 1` elements
+    /// with values returned by `f` if it is too short.
+    ///
+    /// # Panics
+    /// Panics if `index` is `usize::MAX`.
+    ///
+    /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+    pub fn get_or_insert_with(&self, index: usize, f: impl FnMut() -> T) -> T
+    where
+        T: Clone,
+    {
+        self.with_mut(|vec| {
+            if index >= vec.len() {
+                vec.resize_with(index.checked_add(1).expect("index overflow"), f);
+            }
+
+            vec[index].clone()
+        })
+    }
+
+    /// Shortens the vector to `len` elements and shrinks its capacity as much as possible.
+    pub fn truncate_and_shrink(&self, len: usize) {
+        self.with_mut(|vec| {
+            vec.truncate(len);
+            vec.shrink_to_fit();
+        })
+    }
+
+    /// Clones the elements into a new vector rotated left by `mid` places.
+    ///
+    /// # Panics
+    /// Panics if `mid > len`. See [slice::rotate_left](slice::rotate_left) for more information.
+    pub fn rotated_left(&self, mid: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = self.to_vec();
+        vec.rotate_left(mid);
+        vec
+    }
+
+    /// Clones the elements into a new vector rotated right by `k` places.
+    ///
+    /// # Panics
+    /// Panics if `k > len`. See [slice::rotate_right](slice::rotate_right) for more information.
+    pub fn rotated_right(&self, k: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = self.to_vec();
+        vec.rotate_right(k);
+        vec
+    }
+
+    /// Clones the elements into a new vector in reverse order.
+    pub fn reversed(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| vec.iter().rev().cloned().collect())
+    }
+
+    /// Clones the elements into a new sorted vector.
+    pub fn sorted(&self) -> Vec<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut vec = self.to_vec();
+        vec.sort();
+        vec
+    }
+
+    /// Clones the elements into a new vector sorted with the comparator `f`.
+    ///
+    /// `f` is only called on the copy, so it may access this `VecCell`.
+    pub fn sorted_by(&self, f: impl FnMut(&T, &T) -> Ordering) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = self.to_vec();
+        vec.sort_by(f);
+        vec
+    }
+
+    /// Clones the elements into a new vector with consecutive duplicates removed.
+    pub fn dedup_cloned(&self) -> Vec<T>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut vec = self.to_vec();
+        vec.dedup();
+        vec
+    }
+
+    /// Returns `true` if `needle` occurs as a contiguous run of elements.
+    ///
+    /// An empty `needle` is always contained.
+    pub fn contains_subslice(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        needle.is_empty() || self.with_ref(|vec| vec.windows(needle.len()).any(|w| w == needle))
+    }
+
+    /// Returns the index of the first element equal to `x`.
+    pub fn index_of(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.with_ref(|vec| vec.iter().position(|e| e == x))
+    }
+
+    /// Returns the index of the last element equal to `x`.
+    pub fn last_index_of(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.with_ref(|vec| vec.iter().rposition(|e| e == x))
+    }
+
+    /// Clones the elements into a [`Cow`].
+    ///
+    /// This is always [`Cow::Owned`]: borrowing would be unsound, as the `VecCell`
+    /// can be mutated while the borrow is alive.
+    pub fn snapshot_cow(&self) -> Cow<'_, [T]>
+    where
+        T: Clone,
+    {
+        Cow::Owned(self.to_vec())
+    }
+
+    /// Retains only the elements matching `pred` in a single pass and returns the removed
+    /// ones in order.
+    ///
+    /// `pred` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+    pub fn retain_collect(&self, mut pred: impl FnMut(&T) -> bool) -> Vec<T> {
+        self.extract_if(|x| !pred(x))
+    }
+
+    /// Swaps the elements at `a` and `b`.
+    ///
+    /// Returns `false` and does nothing if either index is out of bounds.
+    pub fn try_swap(&self, a: usize, b: usize) -> bool {
+        self.with_mut(|vec| {
+            if a >= vec.len() || b >= vec.len() {
+                return false;
+            }
+
+            vec.swap(a, b);
+            true
+        })
+    }
+
+    /// Fills the elements in `range` with clones of `value`.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds.
+    pub fn fill_range<R: RangeBounds<usize>>(&self, range: R, value: T)
+    where
+        T: Clone,
+    {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.with_mut(|vec| vec[range].fill(value))
+    }
+
+    delegate_vec_methods! {
+        with_ref;
+        capacity() -> usize
+    }
+
+    delegate_vec_methods! {
+        with_mut;
+        clear() -> (),
+        insert(index: usize, value: T) -> (),
+        pop() -> Option<T>,
+        ///
+        /// `pred` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        pop_if(pred: impl FnOnce(&mut T) -> bool) -> Option<T>,
+        push(value: T) -> (),
+        reserve(additional: usize) -> (),
+        reserve_exact(additional: usize) -> (),
+        shrink_to(min_capacity: usize) -> (),
+        shrink_to_fit() -> (),
+        swap_remove(index: usize) -> T,
+        truncate(len: usize) -> (),
+        dedup() -> () where T: PartialEq,
+        ///
+        /// `same` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        dedup_by(same: impl FnMut(&mut T, &mut T) -> bool) -> (),
+        ///
+        /// `key` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        dedup_by_key<K: PartialEq>(key: impl FnMut(&mut T) -> K) -> (),
+        extend(it: impl IntoIterator<Item = T>) -> (),
+        extend_from_slice(other: &[T]) -> () where T: Clone,
+        extend_from_within<R: RangeBounds<usize>>(src: R) -> () where T: Clone,
+        remove(index: usize) -> T,
+        resize(new_len: usize, value: T) -> () where T: Clone,
+        ///
+        /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        resize_with(new_len: usize, f: impl FnMut() -> T) -> (),
+        try_reserve(additional: usize) -> Result<(), TryReserveError>,
+        try_reserve_exact(additional: usize) -> Result<(), TryReserveError>,
+        ///
+        /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        retain(f: impl FnMut(&T) -> bool) -> (),
+        ///
+        /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        retain_mut(f: impl FnMut(&mut T) -> bool) -> ()
+    }
+
+    delegate_slice_methods! {
+        with_ref;
+        len() -> usize,
+        is_empty() -> bool,
+        as_ptr() -> *const T,
+        binary_search(x: &T) -> Result<usize, usize> where T: Ord,
+        contains(x: &T) -> bool where T: PartialEq,
+        starts_with(other: &[T]) -> bool where T: PartialEq,
+        ///
+        /// `f` must not mutate this `VecCell`.
+        binary_search_by(f: impl FnMut(&T) -> Ordering) -> Result<usize, usize>,
+        ///
+        /// `f` must not mutate this `VecCell`.
+        binary_search_by_key<B: Ord>(b: &B, f: impl FnMut(&T) -> B) -> Result<usize, usize>,
+        ///
+        /// `pred` must not mutate this `VecCell`.
+        partition_point(pred: impl FnMut(&T) -> bool) -> usize,
+        is_sorted() -> bool where T: PartialOrd,
+        ///
+        /// `f` must not mutate this `VecCell`.
+        is_sorted_by_key<K: PartialOrd>(f: impl FnMut(&T) -> K) -> bool,
+        ends_with(other: &[T]) -> bool where T: PartialEq
+    }
+
+    delegate_slice_methods! {
+        with_mut;
+        as_mut_ptr() -> *mut T,
+        copy_within<R: RangeBounds<usize>>(src: R, dest: usize) -> () where T: Copy,
+        fill(value: T) -> () where T: Clone,
+        ///
+        /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        fill_with(f: impl FnMut() -> T) -> (),
+        reverse() -> (),
+        rotate_left(mid: usize) -> (),
+        rotate_right(k: usize) -> (),
+        sort() -> () where T: Ord,
+        sort_unstable() -> () where T: Ord,
+        swap(a: usize, b: usize) -> (),
+        ///
+        /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        sort_by(f: impl FnMut(&T, &T) -> Ordering) -> (),
+        ///
+        /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        sort_by_key<K: Ord>(f: impl FnMut(&T) -> K) -> (),
+        ///
+        /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        sort_unstable_by(f: impl FnMut(&T, &T) -> Ordering) -> (),
+        ///
+        /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        sort_unstable_by_key<K: Ord>(f: impl FnMut(&T) -> K) -> (),
+        ///
+        /// `f` must not access this `VecCell`, see [`with_mut`](Self::with_mut).
+        sort_by_cached_key<K: Ord>(f: impl FnMut(&T) -> K) -> ()
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T, A: Allocator> VecCell<T, A> {
+    /// Constructs a new, empty `VecCell` with the given allocator.
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            inner: UnsafeCell::new(Vec::new_in(alloc)),
+            borrow: BorrowFlag::new(),
+        }
+    }
+
+    /// Constructs a new, empty `VecCell` with at least the given capacity and the given allocator.
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            inner: UnsafeCell::new(Vec::with_capacity_in(capacity, alloc)),
+            borrow: BorrowFlag::new(),
+        }
+    }
+
+    /// Returns a clone of the allocator of the inner vector.
+    ///
+    /// Like the elements, the allocator is cloned rather than borrowed,
+    /// as a reference to it would alias any later mutable access to the inner vector.
+    pub fn allocator(&self) -> A
+    where
+        A: Clone,
+    {
+        self.with_ref(|vec| vec.allocator().clone())
+    }
+}
+
+impl<T: Clone> VecCell<Vec<T>> {
+    /// Flattens the inner vectors into a single `Vec`.
+    ///
+    /// See [slice::concat] for more information.
+    pub fn concat(&self) -> Vec<T> {
+        self.with_ref(|vec| vec.concat())
+    }
+
+    /// Flattens the inner vectors into a single `Vec`, placing `sep` between each of them.
+    ///
+    /// See [slice::join] for more information.
+    pub fn join(&self, sep: &T) -> Vec<T> {
+        self.with_ref(|vec| vec.join(sep))
+    }
+}
+
+impl<S: Borrow<str>> VecCell<S> {
+    /// Concatenates the strings into a single `String`, placing `sep` between each of them.
+    ///
+    /// See [slice::join] for more information.
+    pub fn join_str(&self, sep: &str) -> String {
+        self.with_ref(|vec| vec.join(sep))
+    }
+}
+
+impl<T> From<Vec<T>> for VecCell<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self {
+            inner: UnsafeCell::new(vec),
+            borrow: BorrowFlag::new(),
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VecCell<T> {
+    fn from(array: [T; N]) -> Self {
+        Self::from(Vec::from(array))
+    }
+}
+
+impl<T> From<VecCell<T>> for Vec<T> {
+    fn from(vec_cell: VecCell<T>) -> Self {
+        vec_cell.into_inner()
+    }
+}
+
+impl<T: PartialEq> PartialEq for VecCell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.with_ref(|a| other.with_ref(|b| a == b))
+    }
+}
+
+impl<T: Eq> Eq for VecCell<T> {}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for VecCell<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.with_ref(|vec| vec == other)
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for VecCell<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.with_ref(|vec| vec.as_slice() == other)
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for VecCell<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.with_ref(|vec| vec.as_slice() == *other)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for VecCell<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.with_ref(|a| other.with_ref(|b| a.partial_cmp(b)))
+    }
+}
+
+impl<T: Ord> Ord for VecCell<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.with_ref(|a| other.with_ref(|b| a.cmp(b)))
+    }
+}
+
+/// Like with other interior mutable types, mutating a `VecCell` while it is used as a
+/// key in a `HashMap`/`HashSet` is a logic error.
+impl<T: Hash> Hash for VecCell<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.with_ref(|vec| vec.hash(state))
+    }
+}
+
+impl<T> FromIterator<T> for VecCell<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(Vec::from_iter(iter))
+    }
+}
+
+impl<T> Extend<T> for VecCell<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.get_mut().extend(iter)
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for VecCell<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.get_mut().extend(iter)
+    }
+}
+
+impl<T> IntoIterator for VecCell<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_inner().into_iter()
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a VecCell<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::prelude::rust_2021::*;
+    use std::{format, println, vec};
+
+    #[test]
+    fn it_works() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        x.push(12);
+        x.push(34);
+
+        assert_eq!(x.into_inner().as_slice(), &[1, 2, 3, 12, 34]);
+    }
+
+    #[test]
+    fn iter_forward() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.iter().collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn iter_backward() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.iter().rev().collect::<Vec<_>>(), [3, 2, 1]);
+    }
+
+    #[test]
+    fn iter_interleaved() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4];
+        let mut it = x.iter();
+        assert_eq!(it.next(), Some(1));
+        assert_eq!(it.next_back(), Some(4));
+        assert_eq!(it.next_back(), Some(3));
+        assert_eq!(it.next(), Some(2));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn iter_empty() {
+        let x: VecCell<u8> = VecCell::new();
+        let mut it = x.iter();
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn iter_size_hint() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4];
+        let mut it = x.iter();
+        assert_eq!(it.size_hint(), (4, Some(4)));
+        it.next();
+        it.next_back();
+        assert_eq!(it.len(), 2);
+        x.truncate(1);
+        assert_eq!(it.len(), 0);
+    }
+
+    #[test]
+    fn iter_collect_capacity() {
+        let x: VecCell<u64> = VecCell::from((0..10).collect::<Vec<_>>());
+        let v = Vec::from_iter(x.iter());
+        assert_eq!(v.capacity(), 10);
+    }
+
+    #[test]
+    fn iter_fused() {
+        let x: VecCell<u8> = vec_cell![1];
+        let mut it = x.iter();
+        assert_eq!(it.next(), Some(1));
+        assert_eq!(it.next(), None);
+        x.push(2);
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+        assert_eq!(it.len(), 0);
+
+        let mut fused = x.iter().fuse();
+        assert_eq!(fused.by_ref().count(), 2);
+        x.push(3);
+        assert_eq!(fused.next(), None);
+    }
+
+    #[test]
+    fn iter_push_during_iteration() {
+        let x: VecCell<u8> = vec_cell![1];
+        let mut visited = Vec::new();
+        for v in x.iter() {
+            if v < 3 {
+                x.push(v + 1);
+            }
+            visited.push(v);
+        }
+        assert_eq!(visited, [1, 2, 3]);
+
+        let mut it = x.iter();
+        assert_eq!(it.len(), 3);
+        x.push(4);
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.next_back(), Some(4));
+        x.push(5);
+        assert_eq!(it.collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn iter_by_ref() {
+        let x: VecCell<String> = vec_cell![String::from("a"), String::from("b")];
+        let mut out = String::new();
+        for s in &x {
+            out.push_str(&s);
+        }
+        assert_eq!(out, "ab");
+        assert_eq!(x.into_inner(), ["a", "b"]);
+    }
+
+    #[test]
+    fn snapshot() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        let snapshot = x.snapshot();
+        x.push(4);
+        assert_eq!(snapshot, [1, 2, 3]);
+        assert_eq!(x.snapshot(), x.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_snapshot_vs_iter() {
+        use std::time::Instant;
+
+        let x: VecCell<String> = VecCell::from(vec![String::from("vec-cell"); 1_000_000]);
+
+        let start = Instant::now();
+        let collected = x.iter().collect::<Vec<_>>();
+        let iter_time = start.elapsed();
+
+        let start = Instant::now();
+        let snapshot = x.snapshot();
+        let snapshot_time = start.elapsed();
+
+        assert_eq!(collected, snapshot);
+        println!("iter().collect(): {iter_time:?}, snapshot(): {snapshot_time:?}");
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_iter_copied_vs_iter() {
+        use std::time::Instant;
+
+        let x: VecCell<u64> = (0..1_000_000).collect();
+
+        let start = Instant::now();
+        let iter_sum = x.iter().sum::<u64>();
+        let iter_time = start.elapsed();
+
+        let start = Instant::now();
+        let copied_sum = x.iter_copied().sum::<u64>();
+        let copied_time = start.elapsed();
+
+        assert_eq!(iter_sum, copied_sum);
+        println!("iter(): {iter_time:?}, iter_copied(): {copied_time:?}");
+    }
+
+    #[test]
+    fn iter_copied() {
+        let x: VecCell<u64> = vec_cell![1, 2, 3];
+        let iter = x.iter_copied();
+        x.push(4);
+        assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+        assert!(x.iter_copied().eq(x.iter()));
+    }
+
+    #[test]
+    fn with_mut() {
+        let x: VecCell<u8> = vec_cell![3, 1, 2];
+        let len = x.with_mut(|v| {
+            v.push(4);
+            v.sort();
+            v.retain(|&n| n != 2);
+            v.len()
+        });
+        assert_eq!(len, 3);
+        assert_eq!(x.into_inner(), [1, 3, 4]);
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn checked_sequential_use() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        x.with_mut(|v| v.push(3));
+        x.push(4);
+        {
+            let mut borrowed = x.try_borrow_mut().unwrap();
+            borrowed.push(5);
+        }
+        assert_eq!(x.get(4), Some(5));
+        assert!(x.try_borrow_mut().is_ok());
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn checked_try_borrow_mut_while_borrowed() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        x.with_mut(|_| assert!(x.try_borrow_mut().is_err()));
+        let _borrowed = x.try_borrow_mut().unwrap();
+        assert!(x.try_borrow_mut().is_err());
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn checked_nested_mutation_panics() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        x.with_mut(|_| x.push(3));
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    #[should_panic(expected = "mutably borrowed")]
+    fn checked_access_during_borrow_panics() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        let _borrowed = x.try_borrow_mut().unwrap();
+        x.get(0);
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn checked_mutation_during_borrow_panics() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        let _borrowed = x.try_borrow_mut().unwrap();
+        x.clear();
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn checked_reentrant_splice_panics() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        x.splice(
+            ..,
+            core::iter::from_fn(|| {
+                x.push(0);
+                None
+            }),
+        );
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn checked_reads_during_shared_access() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        assert_eq!(x.position(|&n| n as usize == x.len()), Some(1));
+        assert_eq!(x.map(|n| x.contains(n)), [true, true][..]);
+        assert_eq!(x.binary_search_by(|n| n.cmp(&(x.len() as u8))), Ok(1));
+        assert_eq!(x.partition_point(|&n| (n as usize) < x.len()), 1);
+        assert!(x.is_sorted_by_key(|&n| n as usize + x.len()));
+    }
+
+    #[test]
+    fn eq() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x, vec_cell![1, 2, 3]);
+        assert_ne!(x, vec_cell![1, 2]);
+        assert_ne!(x, vec_cell![1, 2, 4]);
+        assert_eq!(x, x);
+        assert_eq!(x, vec![1, 2, 3]);
+        assert_eq!(x, [1, 2, 3][..]);
+        assert_eq!(x, &[1, 2, 3][..]);
+        assert_ne!(x, &[1, 2, 3, 4][..]);
+    }
+
+    #[test]
+    fn ord() {
+        let a: VecCell<i32> = vec_cell![1, 2];
+        let b: VecCell<i32> = vec_cell![1, 2, 3];
+        let c: VecCell<i32> = vec_cell![1, 3];
+        assert!(a < b);
+        assert!(b < c);
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+        assert_eq!(a.partial_cmp(&c), Some(Ordering::Less));
+
+        let mut cells = vec![c.clone(), a.clone(), b.clone()];
+        cells.sort();
+        assert_eq!(cells, [a, b, c]);
+    }
+
+    #[test]
+    #[allow(clippy::mutable_key_type)]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(vec_cell![1, 2]));
+        assert!(set.insert(vec_cell![1, 2, 3]));
+        assert!(!set.insert(vec_cell![1, 2]));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&vec_cell![1, 2, 3]));
+        assert!(!set.contains(&vec_cell![3]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let x: VecCell<i32> = vec_cell![1, 2, 3];
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(json, "[1,2,3]");
+        assert_eq!(serde_json::from_str::<VecCell<i32>>(&json).unwrap(), x);
+
+        let x: VecCell<String> = vec_cell![String::from("a"), String::from("b")];
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(serde_json::from_str::<VecCell<String>>(&json).unwrap(), x);
+
+        let x: VecCell<i32> = VecCell::new();
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(json, "[]");
+        assert_eq!(serde_json::from_str::<VecCell<i32>>(&json).unwrap(), x);
+
+        let x: VecCell<i32> = VecCell::from((0..10_000).collect::<Vec<_>>());
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(serde_json::from_str::<VecCell<i32>>(&json).unwrap(), x);
+    }
+
+    #[test]
+    fn extend_trait() {
+        #[derive(Debug, PartialEq)]
+        struct NotClone(u8);
+
+        let mut x: VecCell<NotClone> = VecCell::new();
+        Extend::extend(&mut x, (0..5).map(NotClone));
+        assert_eq!(x.len(), 5);
+        assert_eq!(x.into_inner()[4], NotClone(4));
+
+        let mut x: VecCell<u8> = vec_cell![1];
+        Extend::extend(&mut x, &[2, 3]);
+        assert_eq!(x, [1, 2, 3][..]);
+    }
+
+    #[test]
+    fn from_iter() {
+        let x: VecCell<u8> = (0..3).collect();
+        assert_eq!(x.into_inner(), [0, 1, 2]);
+
+        let x: VecCell<String> = (0..3).map(|n| n.to_string()).collect();
+        assert_eq!(x.into_inner(), ["0", "1", "2"]);
+
+        let x: VecCell<u8> = std::iter::empty().collect();
+        assert!(x.into_inner().is_empty());
+    }
+
+    #[test]
+    fn from_array() {
+        let x: VecCell<u8> = VecCell::from([]);
+        assert!(x.is_empty());
+
+        let x = VecCell::from([1, 2, 3]);
+        assert_eq!(x, [1, 2, 3][..]);
+
+        let x = VecCell::from([Box::new(1), Box::new(2)]);
+        assert_eq!(*x.pop().unwrap(), 2);
+    }
+
+    #[test]
+    fn extend_not_clone() {
+        let x: VecCell<Box<u32>> = VecCell::new();
+        x.extend(vec![Box::new(1), Box::new(2)]);
+        assert_eq!(x.into_inner(), [Box::new(1), Box::new(2)]);
+    }
+
+    #[test]
+    fn get_mut() {
+        fn sort(x: &mut VecCell<u8>) {
+            x.get_mut().sort();
+        }
+
+        let mut x: VecCell<u8> = vec_cell![3, 1, 2];
+        sort(&mut x);
+        assert_eq!(x, [1, 2, 3][..]);
+    }
+
+    #[test]
+    fn set() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.set(1, 20), Some(2));
+        assert_eq!(x.set(3, 40), None);
+        assert_eq!(x, [1, 20, 3][..]);
+    }
+
+    #[test]
+    fn update() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.update(0, |n| *n += 10), Some(()));
+        assert_eq!(x.update(3, |n| *n += 10), None);
+        assert_eq!(x, [11, 2, 3][..]);
+
+        let x: VecCell<String> = vec_cell![String::from("foo")];
+        assert_eq!(
+            x.update(0, |s| {
+                s.push_str("bar");
+                s.len()
+            }),
+            Some(6)
+        );
+        assert_eq!(x.get(0).unwrap(), "foobar");
+    }
+
+    #[test]
+    fn retain() {
+        let x: VecCell<u8> = (0..10).collect();
+        let capacity = x.capacity();
+        x.retain(|n| n % 2 == 0);
+        assert_eq!(x, [0, 2, 4, 6, 8][..]);
+        assert_eq!(x.capacity(), capacity);
+
+        x.retain(|_| true);
+        assert_eq!(x, [0, 2, 4, 6, 8][..]);
+
+        x.retain(|_| false);
+        assert!(x.is_empty());
+        assert_eq!(x.capacity(), capacity);
+    }
+
+    #[test]
+    fn retain_mut() {
+        let x: VecCell<u8> = (0..10).collect();
+        x.retain_mut(|n| {
+            let keep = *n % 2 == 0;
+            *n *= 2;
+            keep
+        });
+
+        assert_eq!(x, [0, 4, 8, 12, 16][..]);
+    }
+
+    #[test]
+    fn sort_by() {
+        let x: VecCell<u8> = vec_cell![2, 3, 1];
+        x.sort_by(|a, b| b.cmp(a));
+        assert_eq!(x, [3, 2, 1][..]);
+        x.sort_unstable_by(|a, b| a.cmp(b));
+        assert_eq!(x, [1, 2, 3][..]);
+
+        let x: VecCell<&str> = vec_cell!["ccc", "a", "bb"];
+        x.sort_by_key(|s| s.len());
+        assert_eq!(x, ["a", "bb", "ccc"][..]);
+
+        #[derive(Debug, PartialEq)]
+        struct Person {
+            age: u8,
+        }
+
+        let x = VecCell::from([Person { age: 30 }, Person { age: 20 }]);
+        x.sort_unstable_by_key(|p| p.age);
+        assert_eq!(x, [Person { age: 20 }, Person { age: 30 }][..]);
+    }
+
+    #[test]
+    fn sort_by_cached_key() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = AtomicUsize::new(0);
+        let x: VecCell<&str> = vec_cell!["10", "2", "33", "4"];
+        x.sort_by_cached_key(|s| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            s.parse::<u32>().unwrap()
+        });
+        assert_eq!(x, ["2", "4", "10", "33"][..]);
+        assert_eq!(calls.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn binary_search_by() {
+        struct Item {
+            id: u32,
+        }
+
+        let x = VecCell::from([Item { id: 1 }, Item { id: 3 }, Item { id: 5 }]);
+        assert_eq!(x.binary_search_by(|item| item.id.cmp(&3)), Ok(1));
+        assert_eq!(x.binary_search_by(|item| item.id.cmp(&4)), Err(2));
+        assert_eq!(x.binary_search_by_key(&5, |item| item.id), Ok(2));
+        assert_eq!(x.binary_search_by_key(&0, |item| item.id), Err(0));
+        assert_eq!(x.binary_search_by_key(&6, |item| item.id), Err(3));
+    }
+
+    #[test]
+    fn partition_point() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4, 5];
+        assert_eq!(x.partition_point(|_| true), 5);
+        assert_eq!(x.partition_point(|_| false), 0);
+        assert_eq!(x.partition_point(|&n| n < 3), 2);
+    }
+
+    #[test]
+    fn append() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        let y: VecCell<u8> = vec_cell![3, 4];
+        x.append(&y);
+        assert_eq!(x, [1, 2, 3, 4][..]);
+        assert!(y.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot append a VecCell to itself")]
+    fn append_self() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        x.append(&x);
+    }
+
+    #[test]
+    fn clear() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        let capacity = x.capacity();
+        x.clear();
+        assert!(x.is_empty());
+        assert_eq!(x.capacity(), capacity);
+        x.clear();
+        assert!(x.is_empty());
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let x: VecCell<u8> = VecCell::with_capacity(100);
+        x.extend([1, 2, 3]);
+        x.shrink_to_fit();
+        assert!(x.capacity() >= 3 && x.capacity() < 100);
+    }
+
+    #[test]
+    fn resize_with_fill_with() {
+        let x: VecCell<Vec<u8>> = VecCell::new();
+        x.resize_with(2, Default::default);
+        assert_eq!(x, [vec![], vec![]][..]);
+        x.resize_with(1, || unreachable!());
+        assert_eq!(x.len(), 1);
+
+        let x: VecCell<u8> = VecCell::from([0; 4]);
+        let mut counter = 0;
+        x.fill_with(|| {
+            counter += 1;
+            counter
+        });
+        assert_eq!(x, [1, 2, 3, 4][..]);
+    }
+
+    #[test]
+    fn splice() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4];
+        assert_eq!(x.splice(1..3, [7, 8, 9]), [2, 3]);
+        assert_eq!(x, [1, 7, 8, 9, 4][..]);
+        assert_eq!(x.splice(1..4, [5]), [7, 8, 9]);
+        assert_eq!(x, [1, 5, 4][..]);
+        assert_eq!(x.splice(..2, []), [1, 5]);
+        assert_eq!(x, [4][..]);
+    }
+
+    #[test]
+    fn extend_from_within() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        x.extend_from_within(..2);
+        assert_eq!(x, [1, 2, 3, 1, 2][..]);
+        x.extend_from_within(..);
+        assert_eq!(x, [1, 2, 3, 1, 2, 1, 2, 3, 1, 2][..]);
+        x.extend_from_within(1..1);
+        assert_eq!(x.len(), 10);
+    }
+
+    #[test]
+    fn to_vec() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        let v = x.to_vec();
+        x.push(3);
+        assert_eq!(v, [1, 2]);
+    }
+
+    #[test]
+    fn into_boxed_slice() {
+        let x: VecCell<u8> = VecCell::with_capacity(10);
+        x.extend([1, 2, 3]);
+        let boxed = x.into_boxed_slice();
+        assert_eq!(boxed.len(), 3);
+        assert_eq!(&*boxed, [1, 2, 3]);
+    }
+
+    #[test]
+    fn leak() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        let capacity = x.capacity();
+        let leaked = x.leak();
+        assert_eq!(leaked, [1, 2, 3]);
+
+        // Reclaim the allocation so the test doesn't actually leak.
+        drop(unsafe { Vec::from_raw_parts(leaked.as_mut_ptr(), leaked.len(), capacity) });
+    }
+
+    #[test]
+    fn swap_cells() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        let y: VecCell<u8> = vec_cell![3];
+        x.swap_cells(&y);
+        assert_eq!(x, [3][..]);
+        assert_eq!(y, [1, 2][..]);
+
+        x.swap_cells(&x);
+        assert_eq!(x, [3][..]);
+    }
+
+    #[test]
+    fn get_many_cloned() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.get_many_cloned([2, 0, 2]), Some([3, 1, 3]));
+        assert_eq!(x.get_many_cloned([0, 3]), None);
+        assert_eq!(x.get_many_cloned([]), Some([]));
+    }
+
+    #[test]
+    fn chunks() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4];
+        assert_eq!(x.chunks(2).collect::<Vec<_>>(), [vec![1, 2], vec![3, 4]]);
+        assert_eq!(x.chunks(3).collect::<Vec<_>>(), [vec![1, 2, 3], vec![4]]);
+        assert_eq!(x.chunks(10).collect::<Vec<_>>(), [vec![1, 2, 3, 4]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_zero() {
+        let x: VecCell<u8> = vec_cell![1];
+        x.chunks(0);
+    }
+
+    #[test]
+    fn windows() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.windows(3).collect::<Vec<_>>(), [vec![1, 2, 3]]);
+        assert_eq!(x.windows(2).collect::<Vec<_>>(), [vec![1, 2], vec![2, 3]]);
+        assert_eq!(x.windows(4).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn windows_zero() {
+        let x: VecCell<u8> = vec_cell![1];
+        x.windows(0);
+    }
+
+    #[test]
+    fn split_at_cloned() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.split_at_cloned(0), (vec![], vec![1, 2, 3]));
+        assert_eq!(x.split_at_cloned(1), (vec![1], vec![2, 3]));
+        assert_eq!(x.split_at_cloned(3), (vec![1, 2, 3], vec![]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_cloned_out_of_bounds() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        x.split_at_cloned(4);
+    }
+
+    #[test]
+    fn take_first_last() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.take_first(2), [1, 2]);
+        assert_eq!(x.take_last(2), [2, 3]);
+        assert_eq!(x.take_first(3), [1, 2, 3]);
+        assert_eq!(x.take_last(3), [1, 2, 3]);
+        assert_eq!(x.take_first(5), [1, 2, 3]);
+        assert_eq!(x.take_last(5), [1, 2, 3]);
+
+        let x: VecCell<u8> = VecCell::new();
+        assert!(x.take_first(1).is_empty());
+        assert!(x.take_last(1).is_empty());
+    }
+
+    #[test]
+    fn position() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 2, 1];
+        assert_eq!(x.position(|&n| n == 2), Some(1));
+        assert_eq!(x.rposition(|&n| n == 2), Some(3));
+        assert_eq!(x.position(|&n| n == 1), Some(0));
+        assert_eq!(x.rposition(|&n| n == 1), Some(4));
+        assert_eq!(x.position(|&n| n == 4), None);
+        assert_eq!(x.rposition(|&n| n == 4), None);
+    }
+
+    #[test]
+    fn find_cloned() {
+        let x: VecCell<String> =
+            vec_cell![String::from("a"), String::from("bb"), String::from("cc")];
+        assert_eq!(x.find_cloned(|s| s.len() == 2).unwrap(), "bb");
+        assert_eq!(x.find_cloned(|s| s.len() == 3), None);
+        assert_eq!(x.find_cloned(|_| true).unwrap(), "a");
+    }
+
+    #[test]
+    fn count_any_all() {
+        let x: VecCell<u8> = VecCell::new();
+        assert_eq!(x.count(|_| true), 0);
+        assert!(!x.any(|_| true));
+        assert!(x.all(|_| false));
+
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4];
+        assert_eq!(x.count(|n| n % 2 == 0), 2);
+        assert!(x.any(|&n| n == 3));
+        assert!(!x.any(|&n| n == 5));
+        assert!(x.all(|&n| n > 0));
+        assert!(!x.all(|&n| n > 1));
+    }
+
+    #[test]
+    fn min_max_cloned() {
+        let x: VecCell<u8> = VecCell::new();
+        assert_eq!(x.min_cloned(), None);
+        assert_eq!(x.max_by_key(|&n| n), None);
+
+        let x: VecCell<u8> = vec_cell![7];
+        assert_eq!(x.min_cloned(), Some(7));
+        assert_eq!(x.max_cloned(), Some(7));
+
+        let x: VecCell<(u8, char)> = vec_cell![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+        assert_eq!(x.min_cloned(), Some((1, 'b')));
+        assert_eq!(x.max_cloned(), Some((2, 'c')));
+        assert_eq!(x.min_by_key(|&(n, _)| n), Some((1, 'b')));
+        assert_eq!(x.max_by_key(|&(n, _)| n), Some((2, 'c')));
+    }
+
+    #[test]
+    fn map() {
+        let x: VecCell<i32> = vec_cell![1, -2];
+        let y = x.map(|n| n.to_string());
+        assert_eq!(y, [String::from("1"), String::from("-2")][..]);
+        assert_eq!(x, [1, -2][..]);
+    }
+
+    #[test]
+    fn filter_map_collect() {
+        let x: VecCell<&str> = vec_cell!["1", "x", "23", "-4"];
+        assert_eq!(x.filter_map_collect(|s| s.parse::<u32>().ok()), [1, 23]);
+        assert!(x.filter_map_collect(|_| None::<u32>).is_empty());
+    }
+
+    #[test]
+    fn for_each_indexed() {
+        let x: VecCell<usize> = vec_cell![4, 5, 6];
+        let mut sum = 0;
+        x.for_each_indexed(|i, n| sum += i * n);
+        assert_eq!(sum, 5 + 2 * 6);
+    }
+
+    #[test]
+    fn fold_reduce() {
+        let x: VecCell<u32> = vec_cell![1, 5, 3];
+        assert_eq!(x.fold(0, |acc, n| acc + n), 9);
+        assert_eq!(x.reduce(|max, &n| max.max(n)), Some(5));
+
+        let x: VecCell<&str> = vec_cell!["a", "b", "c"];
+        assert_eq!(x.fold(String::new(), |acc, s| acc + s), "abc");
+
+        let x: VecCell<u32> = VecCell::new();
+        assert_eq!(x.reduce(|max, &n| max.max(n)), None);
+    }
+
+    #[test]
+    fn dedup_by() {
+        let x: VecCell<String> = ["a", "A", "b", "B", "b", "a"].map(String::from).into();
+        x.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        assert_eq!(x, ["a", "b", "a"].map(String::from)[..]);
+
+        #[derive(Debug, PartialEq)]
+        struct Item {
+            id: u32,
+            name: &'static str,
+        }
+
+        let x = VecCell::from([
+            Item { id: 1, name: "a" },
+            Item { id: 1, name: "b" },
+            Item { id: 2, name: "c" },
+        ]);
+        x.dedup_by_key(|item| item.id);
+        assert_eq!(
+            x,
+            [Item { id: 1, name: "a" }, Item { id: 2, name: "c" }][..]
+        );
+    }
+
+    #[test]
+    fn is_sorted() {
+        let x: VecCell<i32> = vec_cell![1, 2, 2, 3];
+        assert!(x.is_sorted());
+        assert!(!x.is_sorted_by_key(|n| -n));
+
+        let x: VecCell<i32> = vec_cell![3, 2, 1];
+        assert!(!x.is_sorted());
+        assert!(x.is_sorted_by_key(|n| -n));
+
+        let x: VecCell<i32> = vec_cell![1];
+        assert!(x.is_sorted());
+
+        let x: VecCell<i32> = VecCell::new();
+        assert!(x.is_sorted());
+        assert!(x.is_sorted_by_key(|n| -n));
+    }
+
+    #[test]
+    fn select_nth_unstable() {
+        let x: VecCell<u8> = vec_cell![5, 1, 4, 2, 3];
+        let (mut before, median, mut after) = x.select_nth_unstable(2);
+        before.sort();
+        after.sort();
+        assert_eq!((before, median, after), (vec![1, 2], 3, vec![4, 5]));
+        assert_eq!(x.get(2), Some(3));
+
+        let (before, min, _) = x.select_nth_unstable(0);
+        assert!(before.is_empty());
+        assert_eq!(min, 1);
+    }
+
+    #[test]
+    fn concat() {
+        let x: VecCell<Vec<u8>> = vec_cell![vec![1, 2], vec![3], vec![4, 5]];
+        assert_eq!(x.concat(), [1, 2, 3, 4, 5]);
+
+        let x: VecCell<Vec<u8>> = VecCell::new();
+        assert!(x.concat().is_empty());
+    }
+
+    #[test]
+    fn join() {
+        let x: VecCell<Vec<u8>> = vec_cell![vec![1, 2], vec![3]];
+        assert_eq!(x.join(&0), [1, 2, 0, 3]);
+
+        let x: VecCell<&str> = vec_cell!["a", "b", "c"];
+        assert_eq!(x.join_str(", "), "a, b, c");
+
+        let x: VecCell<String> = vec_cell![String::from("a")];
+        assert_eq!(x.join_str(", "), "a");
+    }
+
+    #[test]
+    fn rchunks() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4, 5];
+        assert_eq!(
+            x.rchunks(2).collect::<Vec<_>>(),
+            [vec![4, 5], vec![2, 3], vec![1]]
+        );
+        assert_eq!(x.rchunks(5).collect::<Vec<_>>(), [vec![1, 2, 3, 4, 5]]);
+    }
+
+    #[test]
+    fn chunks_exact() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4, 5];
+        let mut chunks = x.chunks_exact(2);
+        assert_eq!(chunks.next(), Some(vec![1, 2]));
+        assert_eq!(chunks.next(), Some(vec![3, 4]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), [5]);
+        assert!(x.chunks_exact(5).remainder().is_empty());
+    }
+
+    #[test]
+    fn push_within_capacity() {
+        let x: VecCell<u8> = VecCell::with_capacity(2);
+        let capacity = x.capacity();
+        for n in 0..capacity as u8 {
+            assert_eq!(x.push_within_capacity(n), Ok(()));
+        }
+        assert_eq!(x.push_within_capacity(42), Err(42));
+        assert_eq!(x.capacity(), capacity);
+
+        x.reserve(1);
+        assert_eq!(x.push_within_capacity(42), Ok(()));
+        assert_eq!(x.last(), Some(42));
+    }
+
+    #[test]
+    fn pop_if() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        assert_eq!(x.pop_if(|n| *n == 2), Some(2));
+        assert_eq!(x.pop_if(|n| *n == 2), None);
+        assert_eq!(x, [1][..]);
+
+        let x: VecCell<u8> = VecCell::new();
+        assert_eq!(x.pop_if(|_| true), None);
+    }
+
+    #[test]
+    fn remove_matching() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 2];
+        assert_eq!(x.remove_first_match(|&n| n == 2), Some(2));
+        assert_eq!(x.remove_first_match(|&n| n == 4), None);
+        assert_eq!(x, [1, 3, 2][..]);
+
+        let x: VecCell<u8> = (0..10).collect();
+        assert_eq!(x.remove_all_matching(|n| n % 3 == 0), [0, 3, 6, 9]);
+        assert_eq!(x, [1, 2, 4, 5, 7, 8][..]);
+    }
+
+    #[test]
+    fn insert_slice() {
+        let x: VecCell<u8> = vec_cell![3];
+        x.insert_slice(0, &[1, 2]);
+        x.insert_slice(3, &[6]);
+        x.insert_slice(3, &[4, 5]);
+        x.insert_slice(1, &[]);
+        assert_eq!(x, [1, 2, 3, 4, 5, 6][..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_slice_out_of_bounds() {
+        let x: VecCell<u8> = vec_cell![1];
+        x.insert_slice(2, &[2]);
+    }
+
+    #[test]
+    fn push_front() {
+        let x: VecCell<u8> = vec_cell![3];
+        x.push_front(2);
+        x.push(4);
+        x.prepend(&[0, 1]);
+        assert_eq!(x, [0, 1, 2, 3, 4][..]);
+    }
+
+    #[test]
+    fn as_slice() {
+        let x: VecCell<u8> = vec_cell![3, 1, 2];
+        assert_eq!(unsafe { x.as_slice() }, [3, 1, 2]);
+        unsafe { x.as_mut_slice() }.sort();
+        assert_eq!(unsafe { x.as_slice() }, [1, 2, 3]);
+    }
+
+    #[test]
+    fn spare_capacity_mut() {
+        let x: VecCell<u32> = VecCell::new();
+        x.reserve(4);
+        unsafe {
+            for (i, slot) in x.spare_capacity_mut()[..4].iter_mut().enumerate() {
+                slot.write(i as u32 * 10);
+            }
+            x.set_len(4);
+        }
+        assert_eq!(x, [0, 10, 20, 30][..]);
+    }
+
+    #[test]
+    fn try_reserve_exact() {
+        let x: VecCell<u8> = vec_cell![1];
+        assert!(x.try_reserve_exact(10).is_ok());
+        assert!(x.capacity() >= 11);
+        assert!(x.try_reserve_exact(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn try_with_capacity() {
+        let x = VecCell::<u32>::try_with_capacity(16).unwrap();
+        assert!(x.capacity() >= 16);
+        assert!(VecCell::<u32>::try_with_capacity(usize::MAX / mem::size_of::<u32>() + 1).is_err());
+    }
+
+    #[test]
+    fn clone_from() {
+        let mut x: VecCell<u8> = VecCell::with_capacity(100);
+        let ptr = x.as_ptr();
+        x.clone_from(&vec_cell![1, 2, 3]);
+        assert_eq!(x, [1, 2, 3][..]);
+        assert_eq!(x.as_ptr(), ptr);
+        assert!(x.capacity() >= 100);
+    }
+
+    #[test]
+    fn debug() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(format!("{x:?}"), format!("{:?}", vec![1, 2, 3]));
+        assert_eq!(format!("{x:#?}"), format!("{:#?}", vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn display_join() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.display_join(", "), "1, 2, 3");
+
+        let x: VecCell<u8> = VecCell::new();
+        assert_eq!(x.display_join(", "), "");
+    }
+
+    #[cfg(feature = "allocator_api")]
+    #[test]
+    fn allocator_api() {
+        use std::alloc::System;
+
+        let x = VecCell::<u8, System>::new_in(System);
+        let _: System = x.allocator();
+        x.push(1);
+        x.extend_from_slice(&[2, 3]);
+        assert_eq!(x.len(), 3);
+        assert_eq!(x.get(1), Some(2));
+        assert_eq!(x.try_remove(0), Some(1));
+        assert_eq!(x.with_mut(|vec| vec.iter().sum::<u8>()), 5);
+        assert_eq!(x.into_inner(), [2, 3]);
+
+        let x = VecCell::<u8, _>::with_capacity_in(16, System);
+        assert!(x.capacity() >= 16);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn shuffle() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let x: VecCell<u32> = (0..100).collect();
+        x.shuffle(&mut rng);
+        assert_ne!(x, (0..100).collect::<Vec<_>>());
+        x.sort();
+        assert_eq!(x, (0..100).collect::<Vec<_>>());
+
+        let chosen = x.choose(&mut rng).unwrap();
+        assert!(x.contains(&chosen));
+        assert_eq!(VecCell::<u32>::new().choose(&mut rng), None);
+    }
+
+    #[test]
+    fn extract_if() {
+        let x: VecCell<u8> = (0..10).collect();
+        assert_eq!(x.extract_if(|n| *n % 2 == 0), [0, 2, 4, 6, 8]);
+        assert_eq!(x, [1, 3, 5, 7, 9][..]);
+        assert!(x.extract_if(|_| false).is_empty());
+        assert_eq!(x, [1, 3, 5, 7, 9][..]);
+    }
+
+    #[test]
+    fn partition() {
+        let x: VecCell<u8> = (0..6).collect();
+        let (even, odd) = x.partition(|n| n % 2 == 0);
+        assert_eq!(even, [0, 2, 4][..]);
+        assert_eq!(odd, [1, 3, 5][..]);
+        assert_eq!(x.len(), 6);
+
+        let (all, none) = x.partition(|_| true);
+        assert_eq!(all, x);
+        assert!(none.is_empty());
+
+        let (none, all) = x.partition(|_| false);
+        assert!(none.is_empty());
+        assert_eq!(all, x);
+    }
+
+    #[test]
+    fn zip_cloned() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        let y: VecCell<char> = vec_cell!['a', 'b'];
+        assert_eq!(x.zip_cloned(&y), [(1, 'a'), (2, 'b')]);
+
+        let z: VecCell<char> = vec_cell!['a', 'b', 'c'];
+        assert_eq!(x.zip_cloned(&z), [(1, 'a'), (2, 'b')]);
+        assert_eq!(z.zip_cloned(&x), [('a', 1), ('b', 2)]);
+
+        assert!(x.zip_cloned(&VecCell::<char>::new()).is_empty());
+    }
+
+    #[test]
+    fn chunk_by() {
+        let x: VecCell<u8> = vec_cell![1, 1, 2, 2, 2, 3];
+        assert_eq!(x.chunk_by(|&n| n), [vec![1, 1], vec![2, 2, 2], vec![3]]);
+
+        let x: VecCell<&str> = vec_cell!["apple", "avocado", "banana", "cherry", "cranberry"];
+        assert_eq!(
+            x.chunk_by(|s| s.chars().next()),
+            [
+                vec!["apple", "avocado"],
+                vec!["banana"],
+                vec!["cherry", "cranberry"]
+            ]
+        );
+
+        assert!(VecCell::<u8>::new().chunk_by(|&n| n).is_empty());
+    }
+
+    #[test]
+    fn first_last_chunk() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.first_chunk(), Some([1, 2, 3]));
+        assert_eq!(x.last_chunk(), Some([1, 2, 3]));
+        assert_eq!(x.first_chunk(), Some([1, 2]));
+        assert_eq!(x.last_chunk(), Some([2, 3]));
+        assert_eq!(x.first_chunk::<4>(), None);
+        assert_eq!(x.last_chunk::<4>(), None);
+        assert_eq!(x.first_chunk(), Some([]));
+        assert_eq!(x.last_chunk(), Some([]));
+    }
+
+    #[test]
+    fn split_first_last_cloned() {
+        let x: VecCell<u8> = vec_cell![1];
+        assert_eq!(x.split_first_cloned(), Some((1, vec![])));
+        assert_eq!(x.split_last_cloned(), Some((1, vec![])));
+
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.split_first_cloned(), Some((1, vec![2, 3])));
+        assert_eq!(x.split_last_cloned(), Some((3, vec![1, 2])));
+
+        let x: VecCell<u8> = VecCell::new();
+        assert_eq!(x.split_first_cloned(), None);
+        assert_eq!(x.split_last_cloned(), None);
+    }
+
+    #[test]
+    fn macro_repeat() {
+        let x: VecCell<u8> = vec_cell![7; 4];
+        assert_eq!(x.into_inner(), [7, 7, 7, 7]);
+
+        let x: VecCell<u8> = vec_cell![1, 2,];
+        assert_eq!(x.into_inner(), [1, 2]);
+    }
+
+    #[test]
+    fn macro_not_clone() {
+        let x: VecCell<Box<u32>> = vec_cell![Box::new(1), Box::new(2)];
+        assert_eq!(x.into_inner(), [Box::new(1), Box::new(2)]);
+    }
+
+    #[test]
+    fn macro_empty() {
+        let x = vec_cell![];
+        assert_eq!(x.len(), 0);
+        x.push(1u8);
+        assert_eq!(x, [1][..]);
+    }
+
+    #[test]
+    fn push_slice() {
+        let x: VecCell<u8> = VecCell::new();
+        assert_eq!(x.push_slice(&[1, 2]), 0);
+        let start = x.push_slice(&[3, 4]);
+        assert_eq!(start, 2);
+        assert_eq!(x.get(start), Some(3));
+        assert_eq!(x, [1, 2, 3, 4][..]);
+    }
+
+    #[test]
+    fn push_and_index() {
+        let x: VecCell<u8> = VecCell::new();
+        assert_eq!(x.push_and_index(10), 0);
+        assert_eq!(x.push_and_index(20), 1);
+        let index = x.push_and_index(30);
+        assert_eq!(index, 2);
+        assert_eq!(x.get(index), Some(30));
+    }
+
+    #[test]
+    fn try_remove() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4];
+        assert_eq!(x.try_swap_remove(0), Some(1));
+        assert_eq!(x, [4, 2, 3][..]);
+        assert_eq!(x.try_swap_remove(3), None);
+        assert_eq!(x.try_remove(0), Some(4));
+        assert_eq!(x, [2, 3][..]);
+        assert_eq!(x.try_remove(2), None);
+        assert_eq!(x, [2, 3][..]);
+    }
+
+    #[test]
+    fn try_insert() {
+        let x: VecCell<u8> = vec_cell![2];
+        assert_eq!(x.try_insert(1, 3), Ok(()));
+        assert_eq!(x.try_insert(3, 4), Err(4));
+        assert_eq!(x.try_insert(0, 1), Ok(()));
+        assert_eq!(x, [1, 2, 3][..]);
+    }
+
+    #[test]
+    fn get_or_insert_with() {
+        let x: VecCell<u8> = VecCell::new();
+        let mut next = 0;
+        let mut counter = || {
+            next += 1;
+            next
+        };
+        assert_eq!(x.get_or_insert_with(5, &mut counter), 6);
+        assert_eq!(x, [1, 2, 3, 4, 5, 6][..]);
+        assert_eq!(x.get_or_insert_with(2, &mut counter), 3);
+        assert_eq!(x.len(), 6);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            x.get_or_insert_with(usize::MAX, || 0)
+        }));
+        assert!(result.is_err());
+        assert_eq!(x.len(), 6);
+    }
+
+    #[test]
+    fn truncate_and_shrink() {
+        let x: VecCell<u8> = VecCell::with_capacity(100);
+        x.extend_from_slice(&[1, 2, 3, 4]);
+        x.truncate_and_shrink(2);
+        assert_eq!(x, [1, 2][..]);
+        assert_eq!(x.capacity(), 2);
+    }
+
+    #[test]
+    fn enumerate() {
+        let x: VecCell<char> = vec_cell!['a', 'b', 'c'];
+        let mut iter = x.enumerate();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some((0, 'a')));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), [(1, 'b'), (2, 'c')]);
+
+        let x: VecCell<char> = VecCell::new();
+        assert_eq!(x.enumerate().next(), None);
+    }
+
+    #[test]
+    fn iter_step() {
+        let x: VecCell<u8> = (0..10).collect();
+        assert_eq!(x.iter_step(1).collect::<Vec<_>>(), x.snapshot());
+        assert_eq!(x.iter_step(2).collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+        assert_eq!(x.iter_step(20).collect::<Vec<_>>(), [0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be non-zero")]
+    fn iter_step_zero() {
+        let x: VecCell<u8> = vec_cell![1];
+        x.iter_step(0);
+    }
+
+    #[test]
+    fn iter_peekable() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        let mut iter = x.iter_peekable();
+        assert_eq!(iter.peek(), Some(&1));
+        assert_eq!(iter.peek(), Some(&1));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.peek(), Some(&2));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.peek(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_clone() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        let mut iter = x.iter();
+        iter.next();
+        let cloned = iter.clone();
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(cloned.collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(iter.next(), Some(3));
+    }
+
+    #[test]
+    fn rotated() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4];
+        assert_eq!(x.rotated_left(1), [2, 3, 4, 1]);
+        assert_eq!(x.rotated_right(1), [4, 1, 2, 3]);
+        assert_eq!(x.rotated_left(4), [1, 2, 3, 4]);
+        assert_eq!(x, [1, 2, 3, 4][..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotated_out_of_range() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        x.rotated_left(3);
+    }
+
+    #[test]
+    fn reversed() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.reversed(), [3, 2, 1]);
+        assert_eq!(x, [1, 2, 3][..]);
+
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4];
+        assert_eq!(x.reversed(), [4, 3, 2, 1]);
+        assert_eq!(x, [1, 2, 3, 4][..]);
+    }
+
+    #[test]
+    fn sorted() {
+        let x: VecCell<u8> = vec_cell![3, 1, 2];
+        assert_eq!(x.sorted(), [1, 2, 3]);
+        assert_eq!(x.sorted_by(|a, b| b.cmp(a)), [3, 2, 1]);
+        assert_eq!(x, [3, 1, 2][..]);
+    }
+
+    #[test]
+    fn dedup_cloned() {
+        let x: VecCell<u8> = vec_cell![1, 1, 2, 3, 3, 3];
+        assert_eq!(x.dedup_cloned(), [1, 2, 3]);
+        assert_eq!(x, [1, 1, 2, 3, 3, 3][..]);
+
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert_eq!(x.dedup_cloned(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn contains_subslice() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4];
+        assert!(x.contains_subslice(&[1, 2]));
+        assert!(x.contains_subslice(&[2, 3]));
+        assert!(x.contains_subslice(&[3, 4]));
+        assert!(!x.contains_subslice(&[1, 3]));
+        assert!(x.contains_subslice(&[]));
+        assert!(!x.contains_subslice(&[1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn ends_with() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert!(x.ends_with(&[2, 3]));
+        assert!(!x.ends_with(&[1, 2]));
+        assert!(x.ends_with(&[]));
+        assert!(!x.ends_with(&[0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn index_of() {
+        let x: VecCell<u8> = vec_cell![1, 2, 1, 3];
+        assert_eq!(x.index_of(&1), Some(0));
+        assert_eq!(x.last_index_of(&1), Some(2));
+        assert_eq!(x.index_of(&3), Some(3));
+        assert_eq!(x.index_of(&4), None);
+        assert_eq!(x.last_index_of(&4), None);
+    }
+
+    #[test]
+    fn snapshot_cow() {
+        let x: VecCell<u8> = vec_cell![1, 2];
+        let cow = x.snapshot_cow();
+        x.push(3);
+        x.set(0, 10);
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!(*cow, [1, 2]);
+    }
+
+    #[test]
+    fn retain_collect() {
+        let x: VecCell<u8> = (0..10).collect();
+        assert_eq!(x.retain_collect(|n| n % 2 == 0), [1, 3, 5, 7, 9]);
+        assert_eq!(x, [0, 2, 4, 6, 8][..]);
+    }
+
+    #[test]
+    fn try_swap() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3];
+        assert!(x.try_swap(0, 2));
+        assert_eq!(x, [3, 2, 1][..]);
+        assert!(!x.try_swap(0, 3));
+        assert_eq!(x, [3, 2, 1][..]);
+        assert!(x.try_swap(1, 1));
+        assert_eq!(x, [3, 2, 1][..]);
+    }
+
+    #[test]
+    fn fill_range() {
+        let x: VecCell<u8> = vec_cell![0; 4];
+        x.fill_range(1..3, 1);
+        assert_eq!(x, [0, 1, 1, 0][..]);
+        x.fill_range(2..2, 5);
+        assert_eq!(x, [0, 1, 1, 0][..]);
+        x.fill_range(.., 2);
+        assert_eq!(x, [2, 2, 2, 2][..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fill_range_out_of_bounds() {
+        let x: VecCell<u8> = vec_cell![0; 2];
+        x.fill_range(1..3, 1);
+    }
+
+    #[test]
+    fn copy_within() {
+        let x: VecCell<u8> = vec_cell![1, 2, 3, 4, 5];
+        x.copy_within(..2, 1);
+        let mut expected = [1, 2, 3, 4, 5];
+        expected.copy_within(..2, 1);
+        assert_eq!(x, expected[..]);
+        assert_eq!(x, [1, 1, 2, 4, 5][..]);
     }
 }
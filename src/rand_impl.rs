@@ -0,0 +1,23 @@
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+
+use crate::VecCell;
+
+impl<T> VecCell<T> {
+    /// Shuffles the elements in place.
+    ///
+    /// See [SliceRandom::shuffle] for more information.
+    pub fn shuffle(&self, rng: &mut impl Rng) {
+        self.with_mut(|vec| vec.shuffle(rng))
+    }
+
+    /// Clones a random element, or returns `None` if the vector is empty.
+    ///
+    /// See [IndexedRandom::choose] for more information.
+    pub fn choose(&self, rng: &mut impl Rng) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.with_ref(|vec| vec.choose(rng).cloned())
+    }
+}
@@ -0,0 +1,16 @@
+use alloc::vec::Vec;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::VecCell;
+
+impl<T: Serialize> Serialize for VecCell<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.with_ref(|vec| vec.serialize(serializer))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for VecCell<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
@@ -0,0 +1,16 @@
+#![no_std]
+
+extern crate alloc;
+
+use vec_cell::{vec_cell, VecCell};
+
+#[test]
+fn no_std() {
+    let x: VecCell<u8> = vec_cell![1, 2];
+    x.push(3);
+    assert_eq!(x.get(2), Some(3));
+    assert_eq!(x.into_inner(), alloc::vec![1, 2, 3]);
+
+    let x: VecCell<u8> = vec_cell![0; 2];
+    assert_eq!(x.len(), 2);
+}